@@ -94,14 +94,31 @@ pub(crate) fn internal_derive(input: DeriveInput) -> syn::Result<TokenStream> {
 
 fn expand(attr: Vec<Metadata<'_>>, input: &DeriveInput) -> syn::Result<TokenStream> {
     let struct_name = &input.ident;
-    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let is_generic = !input.generics.params.is_empty();
+
+    // Generic structs need their parameters to be 'static (a supertrait of Extractable)
+    // and every nested field type to be Extractable itself.
+    let mut generics = input.generics.clone();
+    if is_generic {
+        let predicates = &mut generics.make_where_clause().predicates;
+        for param in input.generics.type_params() {
+            let param_ident = &param.ident;
+            predicates.push(syn::parse_quote!(#param_ident: 'static));
+        }
+        for attr in &attr {
+            if let Metadata::Nested { target_type, .. } = attr {
+                predicates.push(syn::parse_quote!(#target_type: structecs::Extractable));
+            }
+        }
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
     let metadata_list = attr
         .iter()
         .map(|attr| match attr {
             Metadata::Offset0 { target_type } => {
                 quote::quote! {
-                    structecs::ExtractionMetadata::new::<#target_type>(0),
+                    structecs::ExtractionMetadata::new::<#target_type #ty_generics>(0),
                 }
             }
             Metadata::Nested {
@@ -110,14 +127,34 @@ fn expand(attr: Vec<Metadata<'_>>, input: &DeriveInput) -> syn::Result<TokenStre
             } => {
                 quote::quote! {
                     structecs::ExtractionMetadata::new_nested::<#target_type>(
-                        core::mem::offset_of!(#struct_name, #field_ident),
-                        #target_type::METADATA_LIST,
+                        core::mem::offset_of!(#struct_name #ty_generics, #field_ident),
+                        <#target_type as structecs::Extractable>::METADATA_LIST,
                     ),
                 }
             }
         })
         .collect::<TokenStream>();
 
+    // Generic types cannot be submitted to inventory since they are not monomorphized
+    // here; they are registered lazily on first use instead.
+    // Identifiers cannot tell generic instantiations apart, so generic types get a
+    // `<..>` suffix which makes `ExtractionMetadata::is_has` reject them as targets.
+    let struct_name_str = if is_generic {
+        format!("{}<..>", struct_name)
+    } else {
+        struct_name.to_string()
+    };
+
+    let registration = if is_generic {
+        TokenStream::new()
+    } else {
+        quote::quote! {
+            structecs::__private::submit! {
+                structecs::ExtractableType::new::<#struct_name>()
+            }
+        }
+    };
+
     Ok(quote::quote! {
         impl #impl_generics structecs::Extractable for #struct_name #ty_generics #where_clause {
             const METADATA_LIST: &'static [structecs::ExtractionMetadata] = &[
@@ -127,7 +164,7 @@ fn expand(attr: Vec<Metadata<'_>>, input: &DeriveInput) -> syn::Result<TokenStre
             #[cfg(debug_assertions)]
             const IDENTIFIER: &'static str = {
                 const MODULE_PATH: &str = module_path!();
-                const STRUCT_NAME: &str = stringify!(#struct_name);
+                const STRUCT_NAME: &str = #struct_name_str;
                 const TOTAL: usize = MODULE_PATH.len() + 2 + STRUCT_NAME.len();
                 const FULL_IDENTIFIER_BYTES: [u8; TOTAL] =
                     structecs::__private::concat_str::<TOTAL>(
@@ -138,8 +175,6 @@ fn expand(attr: Vec<Metadata<'_>>, input: &DeriveInput) -> syn::Result<TokenStre
             };
         }
 
-        structecs::__private::submit! {
-            structecs::ExtractableType::new::<#struct_name>()
-        }
+        #registration
     })
 }
@@ -12,11 +12,17 @@ use crate::{Extractable, entity::EntityData};
 /// `Acquirable<T>` provides transparent access to component `T` through `Deref`,
 /// while maintaining ownership of the underlying entity data via reference counting.
 ///
+/// The whole entity stays alive as long as any `Acquirable` pointing into it exists,
+/// even after it has been removed from every collection that stored it. Any type
+/// reachable from the entity (parent, sibling or nested) remains extractable from
+/// such a handle.
+///
 /// # Thread Safety
 ///
 /// `Acquirable<T>` implements `Send` and `Sync` when `T: Send + Sync`, allowing
-/// safe sharing across threads. The internal reference counting is handled by `Arc`,
-/// which provides thread-safe access to immutable data.
+/// safe sharing across threads. The internal reference counting is handled by `Arc`.
+/// Data is only mutated through [`try_map_mut`](Self::try_map_mut), which requires
+/// the handle to be the only one referring to the entity.
 ///
 /// # Examples
 ///
@@ -250,6 +256,110 @@ impl<T: Extractable> Acquirable<T> {
         Some(Acquirable::new_raw(extracted, self.inner.clone()))
     }
 
+    /// Check whether a component type can be extracted from the same entity.
+    ///
+    /// This is cheaper than `extract::<U>().is_some()` since it does not create
+    /// a new `Acquirable`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use structecs::*;
+    ///
+    /// #[derive(Extractable)]
+    /// struct Health {
+    ///     value: u32,
+    /// }
+    ///
+    /// #[derive(Extractable)]
+    /// struct Mana {
+    ///     value: u32,
+    /// }
+    ///
+    /// #[derive(Extractable)]
+    /// #[extractable(health)]
+    /// struct Player {
+    ///     name: String,
+    ///     health: Health,
+    /// }
+    ///
+    /// let player = Acquirable::new(Player {
+    ///     name: "Alice".to_string(),
+    ///     health: Health { value: 100 },
+    /// });
+    ///
+    /// assert!(player.can_extract::<Health>());
+    /// assert!(!player.can_extract::<Mana>());
+    /// ```
+    #[inline(always)]
+    pub fn can_extract<U: Extractable>(&self) -> bool {
+        self.inner.extractor.contains::<U>()
+    }
+
+    /// Get the type name of the entity this component belongs to.
+    ///
+    /// This is the type the entity was created from, regardless of which
+    /// component type `T` this handle points to. The name comes from
+    /// [`std::any::type_name`] and is intended for diagnostics only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use structecs::*;
+    ///
+    /// #[derive(Extractable)]
+    /// struct Entity {
+    ///     id: u32,
+    /// }
+    ///
+    /// #[derive(Extractable)]
+    /// #[extractable(entity)]
+    /// struct Player {
+    ///     name: String,
+    ///     entity: Entity,
+    /// }
+    ///
+    /// let entity: Acquirable<Entity> = Acquirable::new_checked(Player {
+    ///     name: "Alice".to_string(),
+    ///     entity: Entity { id: 1 },
+    /// });
+    ///
+    /// assert!(entity.entity_type_name().ends_with("Player"));
+    /// ```
+    #[inline(always)]
+    pub fn entity_type_name(&self) -> &'static str {
+        (self.inner.extractor.type_name)()
+    }
+
+    /// Create an independent copy of the component in new entity data.
+    ///
+    /// Unlike [`Clone`], which creates another handle to the same entity, this clones
+    /// the component value itself. The copy shares no storage with the original and
+    /// only contains `T`, so types outside of `T` cannot be extracted from it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use structecs::*;
+    ///
+    /// #[derive(Extractable, Clone)]
+    /// struct Entity {
+    ///     id: u32,
+    /// }
+    ///
+    /// let entity = Acquirable::new(Entity { id: 42 });
+    /// let detached = entity.clone_detached();
+    ///
+    /// assert_eq!(detached.id, 42);
+    /// assert!(!entity.ptr_eq(&detached));
+    /// ```
+    pub fn clone_detached(&self) -> Acquirable<T>
+    where
+        T: Clone,
+    {
+        Acquirable::new(T::clone(self))
+    }
+
     /// Create a weak reference to this entity's component.
     ///
     /// The weak reference does not keep the entity alive and can be upgraded
@@ -302,6 +412,42 @@ impl<T: Extractable> Acquirable<T> {
         Arc::ptr_eq(&self.inner, &other.inner)
     }
 
+    /// Get exclusive access to a part of the component if this is the only handle to the entity.
+    ///
+    /// Returns `None` whenever the entity data is shared, i.e. when any other
+    /// `Acquirable` (of any type) or `WeakAcquirable` points to the same entity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use structecs::*;
+    ///
+    /// #[derive(Extractable)]
+    /// struct Player {
+    ///     name: String,
+    ///     health: u32,
+    /// }
+    ///
+    /// let mut player = Acquirable::new(Player {
+    ///     name: "Alice".to_string(),
+    ///     health: 100,
+    /// });
+    ///
+    /// *player.try_map_mut(|player| &mut player.health).unwrap() -= 30;
+    /// assert_eq!(player.health, 70);
+    ///
+    /// let shared = player.clone();
+    /// assert!(player.try_map_mut(|player| &mut player.health).is_none());
+    /// # drop(shared);
+    /// ```
+    pub fn try_map_mut<U: ?Sized>(&mut self, f: impl FnOnce(&mut T) -> &mut U) -> Option<&mut U> {
+        Arc::get_mut(&mut self.inner)?;
+        // SAFETY: Arc::get_mut succeeded, so there are no other strong or weak references
+        // to the entity data. `self` is borrowed mutably for the lifetime of the returned
+        // reference, so no aliasing access can be created through it either.
+        Some(f(unsafe { self.target.as_mut() }))
+    }
+
     /// Get the number of strong references to the entity data.
     ///
     /// This is only available in debug builds for debugging purposes.
@@ -436,13 +582,15 @@ impl<T: Extractable + Debug> Debug for Acquirable<T> {
 //
 // Thread-safety guarantees:
 // - The `target` field is a NonNull<T> pointer that points into EntityData's heap allocation.
-//   The data is immutable after creation (no interior mutability), so shared references
-//   are safe across threads when T: Sync.
+//   Shared access goes through Deref, so shared references are safe across threads when T: Sync.
 // - The `inner` field is an Arc<EntityData>, which provides thread-safe reference counting.
 //   Arc already implements Send when T: Send + Sync.
-// - Since T is accessed only through shared references (via Deref), we require T: Sync.
+// - The only mutable access is `try_map_mut`. It takes `&mut self`, so no other reference
+//   through this handle can exist, and `Arc::get_mut` succeeds only when there are no other
+//   strong or weak handles to the entity. The `&mut T` is therefore exclusive on every thread.
 // - We also require T: Send because the underlying data may be moved between threads
-//   when the last Arc is dropped on a different thread than where it was created.
+//   when the last Arc is dropped on a different thread than where it was created, and
+//   `try_map_mut` can hand out `&mut T` on any thread the handle was sent to.
 unsafe impl<T: Extractable + Send + Sync> Send for Acquirable<T> {}
 unsafe impl<T: Extractable + Send + Sync> Sync for Acquirable<T> {}
 
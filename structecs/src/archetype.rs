@@ -118,6 +118,22 @@ impl<Key: Copy + Eq + Hash, Base: Extractable> Archetype<Key, Base> {
         map.remove(key)
     }
 
+    /// Keep only the entries for which `f` returns `true`, returning the keys of removed entries.
+    ///
+    /// `f` runs under the write lock and must not access this archetype (or a clone of it).
+    pub fn retain(&self, mut f: impl FnMut(Key, &Acquirable<Base>) -> bool) -> Vec<Key> {
+        let mut removed = Vec::new();
+        let mut map = self.map.write();
+        map.retain(|key, value| {
+            let keep = f(*key, value);
+            if !keep {
+                removed.push(*key);
+            }
+            keep
+        });
+        removed
+    }
+
     pub fn contains_key(&self, key: &Key) -> bool {
         let map = self.map.read();
         map.contains_key(key)
@@ -133,6 +149,11 @@ impl<Key: Copy + Eq + Hash, Base: Extractable> Archetype<Key, Base> {
         map.is_empty()
     }
 
+    pub fn capacity(&self) -> usize {
+        let map = self.map.read();
+        map.capacity()
+    }
+
     pub fn clear(&self) {
         let mut map = self.map.write();
         map.clear();
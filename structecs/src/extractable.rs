@@ -5,6 +5,44 @@ use rustc_hash::FxHashMap;
 /// Trait for types that can be extracted from entity data.
 ///
 /// This is typically derived using `#[derive(Extractable)]`.
+///
+/// # Generic Types
+///
+/// The derive also supports generic structs. Type parameters are required to be
+/// `'static`, and every field listed in `#[extractable(...)]` is required to be
+/// `Extractable`. Since generic types cannot be registered at program start, each
+/// instantiation (e.g. `Cell<Position>`) is registered lazily on first use.
+///
+/// In debug builds, all instantiations of a generic type share the same `IDENTIFIER`
+/// (e.g. `module::Cell<..>`). Since compile-time relationship checks cannot tell
+/// `Cell<A>` and `Cell<B>` apart, they always fail for a generic target type, so APIs
+/// such as `Acquirable::extract_checked::<Cell<A>>()` cannot be used with them.
+/// Use the runtime-checked [`Acquirable::extract`](crate::Acquirable::extract) instead.
+///
+/// ```
+/// use structecs::*;
+///
+/// #[derive(Extractable)]
+/// struct Position { x: f32, y: f32 }
+///
+/// #[derive(Extractable)]
+/// struct Meta { revision: u32 }
+///
+/// #[derive(Extractable)]
+/// #[extractable(value, meta)]
+/// struct Cell<T> {
+///     value: T,
+///     meta: Meta,
+/// }
+///
+/// let cell = Acquirable::new(Cell {
+///     value: Position { x: 1.0, y: 2.0 },
+///     meta: Meta { revision: 3 },
+/// });
+///
+/// assert_eq!(cell.extract::<Position>().unwrap().y, 2.0);
+/// assert_eq!(cell.extract::<Meta>().unwrap().revision, 3);
+/// ```
 pub trait Extractable: 'static + Sized {
     /// Metadata describing how to extract components from this type.
     const METADATA_LIST: &'static [ExtractionMetadata];
@@ -16,6 +54,9 @@ pub struct ExtractableType {
     pub type_id: TypeId,
     pub metadata: &'static [ExtractionMetadata],
     pub dropper: unsafe fn(NonNull<u8>),
+    pub type_name: fn() -> &'static str,
+    #[cfg(debug_assertions)]
+    pub identifier: &'static str,
 }
 
 impl ExtractableType {
@@ -30,6 +71,9 @@ impl ExtractableType {
                     drop(boxed);
                 }
             },
+            type_name: std::any::type_name::<T>,
+            #[cfg(debug_assertions)]
+            identifier: T::IDENTIFIER,
         }
     }
 }
@@ -97,9 +141,15 @@ impl ExtractionMetadata {
     /// - `type_name()` is also not const-stable
     /// - String comparison with `IDENTIFIER` (module_path + struct_name) provides
     ///   the same uniqueness guarantee while being const-evaluable
+    ///
+    /// Generic types share one identifier across all instantiations, so this always
+    /// returns `false` when `Target` is generic.
     pub const fn is_has<List: Extractable, Target: Extractable>() -> bool {
         let list = List::METADATA_LIST;
         let target = Target::IDENTIFIER;
+        if Self::is_generic_identifier(target) {
+            return false;
+        }
         let mut idx = 0;
         while list.len() > idx {
             if list[idx].has_val(target) {
@@ -110,6 +160,20 @@ impl ExtractionMetadata {
         false
     }
 
+    /// Whether an identifier belongs to a generic type (marked with a `<..>` suffix by the derive).
+    #[cfg(debug_assertions)]
+    const fn is_generic_identifier(identifier: &str) -> bool {
+        let bytes = identifier.as_bytes();
+        let mut idx = 0;
+        while idx < bytes.len() {
+            if bytes[idx] == b'<' {
+                return true;
+            }
+            idx += 1;
+        }
+        false
+    }
+
     #[cfg(debug_assertions)]
     pub const fn has_val(&self, identifier: &str) -> bool {
         const fn eq_str(a: &str, b: &str) -> bool {
@@ -159,6 +223,58 @@ impl ExtractionMetadata {
         result
     }
 
+    /// Flatten nested metadata into a list of (type identifier, offset) pairs (debug builds only).
+    ///
+    /// This is the identifier-based counterpart of [`flatten`](Self::flatten), intended for
+    /// introspection and tooling such as component tree inspectors. Entries are listed in
+    /// depth-first order of the metadata. Identifiers are not guaranteed to be unique, so
+    /// every entry is kept.
+    #[cfg(debug_assertions)]
+    pub fn flatten_identifiers(list: &[ExtractionMetadata]) -> Vec<(&'static str, usize)> {
+        let mut result = Vec::new();
+        Self::flatten_identifiers_internal(list, 0, None, &mut result);
+        result
+    }
+
+    /// `parent` is the type whose metadata `list` is. Its own offset-0 entry is skipped,
+    /// since the `Nested` entry that led here already reported it.
+    #[cfg(debug_assertions)]
+    fn flatten_identifiers_internal(
+        list: &[ExtractionMetadata],
+        base_offset: usize,
+        parent: Option<TypeId>,
+        result: &mut Vec<(&'static str, usize)>,
+    ) {
+        for metadata in list {
+            match metadata {
+                ExtractionMetadata::Target {
+                    type_id,
+                    identifier,
+                    offset,
+                } => {
+                    if *offset == 0 && parent == Some(*type_id) {
+                        continue;
+                    }
+                    result.push((*identifier, base_offset + *offset));
+                }
+                ExtractionMetadata::Nested {
+                    type_id,
+                    identifier,
+                    offset,
+                    nested,
+                } => {
+                    result.push((*identifier, base_offset + *offset));
+                    Self::flatten_identifiers_internal(
+                        nested,
+                        base_offset + *offset,
+                        Some(*type_id),
+                        result,
+                    );
+                }
+            }
+        }
+    }
+
     fn flatten_internal(
         list: &[ExtractionMetadata],
         base_offset: usize,
@@ -8,6 +8,7 @@ use crate::{ExtractionMetadata, extractable::ExtractableType};
 pub struct Extractor {
     pub(crate) offsets: FxHashMap<TypeId, usize>,
     pub(crate) dropper: unsafe fn(NonNull<u8>),
+    pub(crate) type_name: fn() -> &'static str,
 }
 
 impl Extractor {
@@ -15,9 +16,16 @@ impl Extractor {
         Self {
             offsets: ExtractionMetadata::flatten(target.metadata),
             dropper: target.dropper,
+            type_name: target.type_name,
         }
     }
 
+    /// Check whether type T can be extracted from entity data of this type.
+    #[inline(always)]
+    pub(crate) fn contains<T: 'static>(&self) -> bool {
+        self.offsets.contains_key(&const { TypeId::of::<T>() })
+    }
+
     /// Extract a pointer to a component of type T from entity data.
     ///
     /// # Safety
@@ -1,6 +1,9 @@
 #![doc = include_str!("../README.md")]
 
-use std::{any::TypeId, sync::LazyLock};
+use std::{
+    any::TypeId,
+    sync::{LazyLock, PoisonError, RwLock},
+};
 
 use rustc_hash::FxHashMap;
 // Re-export the derive macro
@@ -63,9 +66,66 @@ pub static GLOBAL_EXTRACTOR_CACHE: LazyLock<FxHashMap<TypeId, extractor::Extract
             .collect()
     });
 
+/// Check whether an extractor exists for `T`.
+///
+/// Non-generic types are registered at program start through `inventory`, so this
+/// returning `false` for one of them means its registration was lost (for example
+/// stripped by the linker). Generic types are registered lazily and only report
+/// `true` after their first use.
+pub fn is_registered<T: Extractable>() -> bool {
+    let type_id = TypeId::of::<T>();
+    GLOBAL_EXTRACTOR_CACHE.contains_key(&type_id)
+        || LAZY_EXTRACTOR_CACHE
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .contains_key(&type_id)
+}
+
+/// List the identifiers of every `Extractable` type registered at program start
+/// (debug builds only).
+///
+/// This does not depend on any instance existing, which makes it useful for tooling
+/// that needs the catalog of known types. Generic types are registered lazily on first
+/// use and are therefore not included.
+#[cfg(debug_assertions)]
+pub fn registered_type_names() -> Vec<&'static str> {
+    inventory::iter::<extractable::ExtractableType>
+        .into_iter()
+        .map(|extractable| extractable.identifier)
+        .collect()
+}
+
+/// Extractors for types that could not be submitted through inventory (generic types),
+/// created on first use and leaked so they live as long as the inventory-backed ones.
+static LAZY_EXTRACTOR_CACHE: LazyLock<RwLock<FxHashMap<TypeId, &'static extractor::Extractor>>> =
+    LazyLock::new(|| RwLock::new(FxHashMap::default()));
+
 pub(crate) fn get_extractor<E: extractable::Extractable>() -> &'static extractor::Extractor {
     let type_id = TypeId::of::<E>();
-    // SAFETY: The GLOBAL_EXTRACTOR_CACHE is populated at program start with all
-    // extractable types via inventory, so the unwrap_unchecked is safe here.
-    unsafe { GLOBAL_EXTRACTOR_CACHE.get(&type_id).unwrap_unchecked() }
+    if let Some(extractor) = GLOBAL_EXTRACTOR_CACHE.get(&type_id) {
+        return extractor;
+    }
+    get_lazy_extractor::<E>(type_id)
+}
+
+#[cold]
+fn get_lazy_extractor<E: extractable::Extractable>(
+    type_id: TypeId,
+) -> &'static extractor::Extractor {
+    if let Some(extractor) = LAZY_EXTRACTOR_CACHE
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get(&type_id)
+    {
+        return extractor;
+    }
+
+    LAZY_EXTRACTOR_CACHE
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .entry(type_id)
+        .or_insert_with(|| {
+            let extractable = extractable::ExtractableType::new::<E>();
+            Box::leak(Box::new(extractor::Extractor::new_type(&extractable)))
+        })
 }
@@ -429,3 +429,191 @@ fn test_archetype_base_type_retrieval() {
     let base2 = archetype.get(&2).unwrap();
     assert_eq!(base2.id, 200);
 }
+
+/// Test extracting a sibling type from a handle whose entry was removed
+#[test]
+fn test_archetype_extract_sibling_after_remove() {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    static DROP_COUNT: AtomicU32 = AtomicU32::new(0);
+
+    #[derive(Extractable, Debug)]
+    struct Base {
+        id: u32,
+    }
+
+    #[derive(Extractable, Debug)]
+    #[extractable(base)]
+    struct Derived {
+        name: String,
+        base: Base,
+    }
+
+    impl Drop for Derived {
+        fn drop(&mut self) {
+            DROP_COUNT.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    DROP_COUNT.store(0, Ordering::SeqCst);
+
+    let archetype = Archetype::<u32, Base>::default();
+    archetype.insert(
+        1,
+        Derived {
+            name: "Held".to_string(),
+            base: Base { id: 7 },
+        },
+    );
+
+    let held = archetype.get(&1).unwrap();
+    assert!(archetype.remove(&1).is_some());
+    assert!(!archetype.contains_key(&1));
+
+    // The handle keeps the entity data alive, so siblings are still reachable
+    assert_eq!(DROP_COUNT.load(Ordering::SeqCst), 0);
+    let derived = held.extract::<Derived>().unwrap();
+    assert_eq!(derived.name, "Held");
+    assert_eq!(derived.extract::<Base>().unwrap().id, 7);
+
+    drop(held);
+    assert_eq!(DROP_COUNT.load(Ordering::SeqCst), 0);
+
+    drop(derived);
+    assert_eq!(DROP_COUNT.load(Ordering::SeqCst), 1);
+}
+
+/// Test archetype capacity reporting
+#[test]
+fn test_archetype_capacity() {
+    #[derive(Extractable, Debug)]
+    struct Entity {
+        id: u32,
+    }
+
+    let archetype = Archetype::<u32, Entity>::default();
+    assert_eq!(archetype.capacity(), 0);
+
+    for i in 0..1000 {
+        archetype.insert(i, Entity { id: i });
+    }
+    let grown = archetype.capacity();
+    assert!(grown >= archetype.len());
+
+    // Clearing keeps the allocated capacity
+    archetype.clear();
+    assert!(archetype.is_empty());
+    assert_eq!(archetype.capacity(), grown);
+
+    archetype.write().shrink_to_fit();
+    assert!(archetype.capacity() < grown);
+}
+
+/// Test retaining entries and pruning an external index with the removed keys
+#[test]
+fn test_archetype_retain() {
+    use std::collections::HashSet;
+
+    #[derive(Extractable, Debug)]
+    struct Entity {
+        health: u32,
+    }
+
+    #[derive(Extractable, Debug)]
+    #[extractable(entity)]
+    struct Zombie {
+        entity: Entity,
+    }
+
+    let archetype = Archetype::<u32, Entity>::default();
+    let mut index: HashSet<u32> = HashSet::new();
+    for i in 0..10 {
+        if i % 3 == 0 {
+            archetype.insert(
+                i,
+                Zombie {
+                    entity: Entity { health: i },
+                },
+            );
+        } else {
+            archetype.insert(i, Entity { health: i });
+        }
+        index.insert(i);
+    }
+
+    // Cull zombies and anything with low health
+    let mut removed =
+        archetype.retain(|_, entity| entity.health >= 2 && entity.extract::<Zombie>().is_none());
+    removed.sort_unstable();
+    assert_eq!(removed, vec![0, 1, 3, 6, 9]);
+
+    for key in &removed {
+        index.remove(key);
+    }
+
+    assert_eq!(archetype.len(), 5);
+    assert_eq!(index.len(), 5);
+    for key in &index {
+        assert!(archetype.contains_key(key));
+    }
+
+    // Retaining everything removes nothing
+    assert!(archetype.retain(|_, _| true).is_empty());
+    assert_eq!(archetype.len(), 5);
+}
+
+/// Test extracting across a nested hierarchy after the archetype itself was dropped
+#[test]
+fn test_archetype_extract_after_archetype_drop() {
+    #[derive(Extractable, Debug)]
+    struct Transform {
+        x: i32,
+    }
+
+    #[derive(Extractable, Debug)]
+    #[extractable(transform)]
+    struct Visual {
+        layer: u8,
+        transform: Transform,
+    }
+
+    #[derive(Extractable, Debug)]
+    #[extractable(visual)]
+    struct Sprite {
+        texture: String,
+        visual: Visual,
+    }
+
+    let archetype = Archetype::<u32, Transform>::default();
+    archetype.insert(
+        1,
+        Sprite {
+            texture: "hero.png".to_string(),
+            visual: Visual {
+                layer: 2,
+                transform: Transform { x: 10 },
+            },
+        },
+    );
+    archetype.insert(
+        2,
+        Visual {
+            layer: 5,
+            transform: Transform { x: 20 },
+        },
+    );
+
+    let sprite_transform = archetype.get(&1).unwrap();
+    let visual_transform = archetype.get(&2).unwrap();
+    drop(archetype);
+
+    // Upward and sideways navigation still works on the detached data
+    let sprite = sprite_transform.extract::<Sprite>().unwrap();
+    assert_eq!(sprite.texture, "hero.png");
+    assert_eq!(sprite.extract::<Visual>().unwrap().layer, 2);
+    assert_eq!(sprite_transform.x, 10);
+
+    let visual = visual_transform.extract::<Visual>().unwrap();
+    assert_eq!(visual.layer, 5);
+    assert!(visual_transform.extract::<Sprite>().is_none());
+}
@@ -84,6 +84,70 @@ fn test_ptr_eq() {
     assert!(!entity1.ptr_eq(&entity3));
 }
 
+#[test]
+fn test_try_map_mut_unique() {
+    #[derive(Extractable)]
+    struct Health {
+        value: u32,
+    }
+
+    #[derive(Extractable)]
+    #[extractable(health)]
+    struct Player {
+        name: String,
+        health: Health,
+    }
+
+    let mut player = Acquirable::new(Player {
+        name: "Alice".to_string(),
+        health: Health { value: 100 },
+    });
+
+    player
+        .try_map_mut(|player| &mut player.name)
+        .unwrap()
+        .push('!');
+    *player
+        .try_map_mut(|player| &mut player.health.value)
+        .unwrap() = 50;
+
+    assert_eq!(player.name, "Alice!");
+    assert_eq!(player.extract::<Health>().unwrap().value, 50);
+}
+
+#[test]
+fn test_try_map_mut_shared() {
+    #[derive(Extractable)]
+    struct Health {
+        value: u32,
+    }
+
+    #[derive(Extractable)]
+    #[extractable(health)]
+    struct Player {
+        health: Health,
+    }
+
+    let mut player = Acquirable::new(Player {
+        health: Health { value: 100 },
+    });
+
+    // Another handle of a different type shares the entity
+    let health = player.extract::<Health>().unwrap();
+    assert!(player.try_map_mut(|player| &mut player.health).is_none());
+    drop(health);
+
+    // A weak reference could be upgraded at any time
+    let weak = player.downgrade();
+    assert!(player.try_map_mut(|player| &mut player.health).is_none());
+    drop(weak);
+
+    *player
+        .try_map_mut(|player| &mut player.health.value)
+        .unwrap() += 1;
+    assert_eq!(player.health.value, 101);
+}
+
 #[test]
 fn test_reference_counting() {
     #[derive(Extractable)]
@@ -268,3 +332,66 @@ fn test_handler_with_multiple_entity_types() {
     assert_eq!(handler_a.call(&entity_a, ()), "A");
     assert_eq!(handler_b.call(&entity_b, ()), 100);
 }
+
+#[test]
+fn test_clone_detached() {
+    use std::sync::{
+        Arc,
+        atomic::{AtomicU32, Ordering},
+    };
+
+    #[derive(Extractable, Debug)]
+    struct Health {
+        value: AtomicU32,
+    }
+
+    impl Clone for Health {
+        fn clone(&self) -> Self {
+            Self {
+                value: AtomicU32::new(self.value.load(Ordering::SeqCst)),
+            }
+        }
+    }
+
+    #[derive(Extractable, Clone, Debug)]
+    #[extractable(health)]
+    struct Player {
+        name: String,
+        health: Health,
+        shared: Arc<AtomicU32>,
+    }
+
+    let player = Acquirable::new(Player {
+        name: "Alice".to_string(),
+        health: Health {
+            value: AtomicU32::new(100),
+        },
+        shared: Arc::new(AtomicU32::new(0)),
+    });
+
+    let detached = player.clone_detached();
+    assert!(!player.ptr_eq(&detached));
+    assert_eq!(detached.name, "Alice");
+
+    // Mutating the copy leaves the original untouched
+    detached.health.value.store(10, Ordering::SeqCst);
+    assert_eq!(player.health.value.load(Ordering::SeqCst), 100);
+    assert_eq!(
+        detached
+            .extract::<Health>()
+            .unwrap()
+            .value
+            .load(Ordering::SeqCst),
+        10
+    );
+
+    // Fields use their own Clone impls, so an Arc field still shares its pointee
+    detached.shared.fetch_add(1, Ordering::SeqCst);
+    assert_eq!(player.shared.load(Ordering::SeqCst), 1);
+
+    // Detaching a nested component only copies that component
+    let health = player.extract::<Health>().unwrap().clone_detached();
+    assert_eq!(health.value.load(Ordering::SeqCst), 100);
+    assert!(health.extract::<Player>().is_none());
+    assert_eq!(player.strong_count(), 1);
+}
@@ -293,3 +293,54 @@ fn test_entity_data_zero_sized() {
 
     assert_eq!(*extracted, ZeroSized);
 }
+
+/// Test reporting the concrete entity type name from any extracted handle
+#[test]
+fn test_entity_type_name() {
+    #[derive(Extractable)]
+    struct Entity {
+        id: u32,
+    }
+
+    #[derive(Extractable)]
+    #[extractable(entity)]
+    struct Player {
+        name: String,
+        entity: Entity,
+    }
+
+    #[derive(Extractable)]
+    #[extractable(entity)]
+    struct Enemy {
+        damage: u32,
+        entity: Entity,
+    }
+
+    let player = Acquirable::new(Player {
+        name: "Alice".to_string(),
+        entity: Entity { id: 1 },
+    });
+    let enemy = Acquirable::new(Enemy {
+        damage: 5,
+        entity: Entity { id: 2 },
+    });
+
+    let player_entity = player.extract::<Entity>().unwrap();
+    let enemy_entity = enemy.extract::<Entity>().unwrap();
+
+    assert_eq!(player.entity_type_name(), std::any::type_name::<Player>());
+    assert_eq!(
+        player_entity.entity_type_name(),
+        std::any::type_name::<Player>()
+    );
+    assert_eq!(
+        enemy_entity.entity_type_name(),
+        std::any::type_name::<Enemy>()
+    );
+
+    let standalone = Acquirable::new(Entity { id: 3 });
+    assert_eq!(
+        standalone.entity_type_name(),
+        std::any::type_name::<Entity>()
+    );
+}
@@ -311,3 +311,368 @@ fn test_clone_with_extraction() {
     // They should point to the same underlying data
     assert!(entity.ptr_eq(&cloned));
 }
+
+/// Test flattening metadata into identifier -> offset mappings
+#[cfg(debug_assertions)]
+#[test]
+fn test_metadata_flatten_identifiers() {
+    #[derive(Extractable)]
+    struct Transform {
+        x: f32,
+        y: f32,
+    }
+
+    #[derive(Extractable)]
+    #[extractable(transform)]
+    struct Visual {
+        color: u32,
+        transform: Transform,
+    }
+
+    #[derive(Extractable)]
+    #[extractable(visual)]
+    struct Sprite {
+        texture: String,
+        visual: Visual,
+    }
+
+    let flattened = ExtractionMetadata::flatten_identifiers(Sprite::METADATA_LIST);
+    let visual_offset = std::mem::offset_of!(Sprite, visual);
+    let transform_offset = visual_offset + std::mem::offset_of!(Visual, transform);
+    assert_eq!(
+        flattened,
+        vec![
+            (Sprite::IDENTIFIER, 0),
+            (Visual::IDENTIFIER, visual_offset),
+            (Transform::IDENTIFIER, transform_offset),
+        ]
+    );
+}
+
+/// Test that flattening identifiers keeps entries with colliding identifiers
+#[cfg(debug_assertions)]
+#[test]
+fn test_metadata_flatten_identifiers_keeps_duplicates() {
+    #[derive(Extractable)]
+    struct Position {
+        x: f32,
+    }
+
+    #[derive(Extractable)]
+    struct Velocity {
+        dx: f32,
+    }
+
+    #[derive(Extractable)]
+    #[extractable(value)]
+    struct Cell<T> {
+        value: T,
+    }
+
+    #[derive(Extractable)]
+    #[extractable(a, b)]
+    struct Holder {
+        a: Cell<Position>,
+        b: Cell<Velocity>,
+    }
+
+    let flattened = ExtractionMetadata::flatten_identifiers(Holder::METADATA_LIST);
+    let a = std::mem::offset_of!(Holder, a);
+    let b = std::mem::offset_of!(Holder, b);
+    let position = a + std::mem::offset_of!(Cell<Position>, value);
+    let velocity = b + std::mem::offset_of!(Cell<Velocity>, value);
+
+    // Both instantiations of Cell are reported, even though they share an identifier
+    assert_eq!(
+        flattened,
+        vec![
+            (Holder::IDENTIFIER, 0),
+            (Cell::<Position>::IDENTIFIER, a),
+            (Position::IDENTIFIER, position),
+            (Cell::<Velocity>::IDENTIFIER, b),
+            (Velocity::IDENTIFIER, velocity),
+        ]
+    );
+}
+
+/// Test deriving Extractable on a generic struct with component fields
+#[test]
+fn test_extractable_generic_struct() {
+    #[derive(Extractable, PartialEq, Debug)]
+    struct Position {
+        x: f32,
+        y: f32,
+    }
+
+    #[derive(Extractable, PartialEq, Debug)]
+    struct Velocity {
+        dx: f32,
+    }
+
+    #[derive(Extractable, PartialEq, Debug)]
+    struct Meta {
+        revision: u32,
+    }
+
+    #[derive(Extractable, PartialEq, Debug)]
+    #[extractable(value, meta)]
+    struct Cell<T> {
+        flag: bool,
+        value: T,
+        meta: Meta,
+    }
+
+    let position_cell = Acquirable::new(Cell {
+        flag: true,
+        value: Position { x: 1.0, y: 2.0 },
+        meta: Meta { revision: 1 },
+    });
+    let velocity_cell = Acquirable::new(Cell {
+        flag: false,
+        value: Velocity { dx: 3.0 },
+        meta: Meta { revision: 2 },
+    });
+
+    assert_eq!(
+        *position_cell.extract::<Position>().unwrap(),
+        Position { x: 1.0, y: 2.0 }
+    );
+    assert_eq!(position_cell.extract::<Meta>().unwrap().revision, 1);
+    assert!(position_cell.extract::<Cell<Position>>().unwrap().flag);
+    assert!(position_cell.extract::<Velocity>().is_none());
+    assert!(position_cell.extract::<Cell<Velocity>>().is_none());
+
+    // Each instantiation gets its own extractor
+    assert_eq!(velocity_cell.extract::<Velocity>().unwrap().dx, 3.0);
+    assert_eq!(velocity_cell.extract::<Meta>().unwrap().revision, 2);
+    assert!(velocity_cell.extract::<Position>().is_none());
+
+    // Generic types can also be nested in other extractable types
+    #[derive(Extractable)]
+    #[extractable(cell)]
+    struct Holder {
+        cell: Cell<Position>,
+    }
+
+    let holder = Acquirable::new(Holder {
+        cell: Cell {
+            flag: true,
+            value: Position { x: 5.0, y: 6.0 },
+            meta: Meta { revision: 9 },
+        },
+    });
+    assert_eq!(holder.extract::<Position>().unwrap().x, 5.0);
+    assert_eq!(holder.extract::<Meta>().unwrap().revision, 9);
+}
+
+#[derive(Extractable)]
+struct RegisteredComponent {
+    value: u32,
+}
+
+#[derive(Extractable)]
+#[extractable(component)]
+struct RegisteredEntity {
+    component: RegisteredComponent,
+}
+
+/// Test enumerating registered type identifiers without any instances
+#[cfg(debug_assertions)]
+#[test]
+fn test_registered_type_names() {
+    let names = registered_type_names();
+
+    assert!(names.contains(&RegisteredComponent::IDENTIFIER));
+    assert!(names.contains(&RegisteredEntity::IDENTIFIER));
+    assert!(names.contains(&"extractable_tests::RegisteredEntity"));
+}
+
+/// Test checking extractability without extracting
+#[test]
+fn test_can_extract() {
+    #[derive(Extractable)]
+    struct Transform {
+        x: f32,
+    }
+
+    #[derive(Extractable)]
+    #[extractable(transform)]
+    struct Visual {
+        layer: u8,
+        transform: Transform,
+    }
+
+    #[derive(Extractable)]
+    #[extractable(visual)]
+    struct Sprite {
+        texture: String,
+        visual: Visual,
+    }
+
+    #[derive(Extractable)]
+    struct Unrelated {
+        value: u32,
+    }
+
+    let sprite = Acquirable::new(Sprite {
+        texture: "hero.png".to_string(),
+        visual: Visual {
+            layer: 1,
+            transform: Transform { x: 0.0 },
+        },
+    });
+
+    assert!(sprite.can_extract::<Sprite>());
+    assert!(sprite.can_extract::<Visual>());
+    assert!(sprite.can_extract::<Transform>());
+    assert!(!sprite.can_extract::<Unrelated>());
+
+    // The check uses the whole entity, not just the handle's own type
+    let transform = sprite.extract::<Transform>().unwrap();
+    assert!(transform.can_extract::<Sprite>());
+    assert_eq!(
+        transform.can_extract::<Visual>(),
+        transform.extract::<Visual>().is_some()
+    );
+
+    let visual = Acquirable::new(Visual {
+        layer: 2,
+        transform: Transform { x: 1.0 },
+    });
+    assert!(visual.can_extract::<Transform>());
+    assert!(!visual.can_extract::<Sprite>());
+}
+
+/// Test navigating from a nested component back up to the concrete entity type
+#[test]
+fn test_extraction_symmetric_navigation() {
+    #[derive(Extractable, Debug)]
+    struct Transform {
+        x: f32,
+    }
+
+    #[derive(Extractable, Debug)]
+    #[extractable(transform)]
+    struct Visual {
+        layer: u8,
+        transform: Transform,
+    }
+
+    #[derive(Extractable, Debug)]
+    #[extractable(visual)]
+    struct Sprite {
+        texture: String,
+        visual: Visual,
+    }
+
+    // A collection of the shared nested type, as a query over Transform would produce
+    let transforms: Vec<Acquirable<Transform>> = vec![
+        Acquirable::new_checked(Sprite {
+            texture: "a.png".to_string(),
+            visual: Visual {
+                layer: 1,
+                transform: Transform { x: 1.0 },
+            },
+        }),
+        Acquirable::new_checked(Visual {
+            layer: 2,
+            transform: Transform { x: 2.0 },
+        }),
+        Acquirable::new(Transform { x: 3.0 }),
+    ];
+
+    let sprites: Vec<_> = transforms
+        .iter()
+        .filter_map(|transform| transform.extract::<Sprite>())
+        .collect();
+    assert_eq!(sprites.len(), 1);
+    assert_eq!(sprites[0].texture, "a.png");
+
+    // Downward navigation from the recovered parent lands on the same data
+    let back = sprites[0].extract::<Transform>().unwrap();
+    assert!(back.ptr_eq(&transforms[0]));
+    assert!(std::ptr::eq(&*back, &*transforms[0]));
+
+    // Intermediate types resolve only where the entity actually contains them
+    assert_eq!(transforms[0].extract::<Visual>().unwrap().layer, 1);
+    assert_eq!(transforms[1].extract::<Visual>().unwrap().layer, 2);
+    assert!(transforms[1].extract::<Sprite>().is_none());
+    assert!(transforms[2].extract::<Visual>().is_none());
+}
+
+/// Test checking whether types have a registered extractor
+#[test]
+fn test_is_registered() {
+    #[derive(Extractable)]
+    struct Plain {
+        value: u32,
+    }
+
+    #[derive(Extractable)]
+    struct Wrapper<T> {
+        value: T,
+    }
+
+    struct NeverUsed;
+
+    assert!(is_registered::<Plain>());
+    assert!(is_registered::<RegisteredEntity>());
+
+    // Generic instantiations are registered on first use
+    assert!(!is_registered::<Wrapper<NeverUsed>>());
+    assert!(!is_registered::<Wrapper<u8>>());
+    let wrapper = Acquirable::new(Wrapper { value: 1u8 });
+    assert_eq!(wrapper.value, 1);
+    assert!(is_registered::<Wrapper<u8>>());
+    assert!(!is_registered::<Wrapper<NeverUsed>>());
+}
+
+/// Test that compile-time relationship checks never accept a generic target
+#[cfg(debug_assertions)]
+#[test]
+fn test_is_has_rejects_generic_targets() {
+    #[derive(Extractable)]
+    struct Position {
+        x: f32,
+    }
+
+    #[derive(Extractable)]
+    struct Velocity {
+        dx: f32,
+    }
+
+    #[derive(Extractable)]
+    #[extractable(value)]
+    struct Cell<T> {
+        value: T,
+    }
+
+    #[derive(Extractable)]
+    #[extractable(cell)]
+    struct Holder {
+        cell: Cell<Position>,
+    }
+
+    assert!(Cell::<Position>::IDENTIFIER.ends_with("Cell<..>"));
+    assert_eq!(Cell::<Position>::IDENTIFIER, Cell::<Velocity>::IDENTIFIER);
+
+    // Instantiations cannot be told apart, so generic targets are always rejected
+    assert!(!ExtractionMetadata::is_has::<Cell<Velocity>, Cell<Position>>());
+    assert!(!ExtractionMetadata::is_has::<Cell<Position>, Cell<Position>>());
+    assert!(!ExtractionMetadata::is_has::<Holder, Cell<Position>>());
+
+    // Non-generic targets are still resolved through generic metadata
+    assert!(ExtractionMetadata::is_has::<Cell<Position>, Position>());
+    assert!(!ExtractionMetadata::is_has::<Cell<Position>, Velocity>());
+    assert!(ExtractionMetadata::is_has::<Holder, Position>());
+    assert!(!ExtractionMetadata::is_has::<Holder, Velocity>());
+
+    // The runtime path still distinguishes instantiations
+    let holder = Acquirable::new(Holder {
+        cell: Cell {
+            value: Position { x: 1.0 },
+        },
+    });
+    assert!(holder.extract::<Cell<Position>>().is_some());
+    assert!(holder.extract::<Cell<Velocity>>().is_none());
+}